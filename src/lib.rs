@@ -1,4 +1,34 @@
+use std::collections::{HashMap, HashSet};
+
 use deunicode::deunicode_char;
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::UnicodeNormalization;
+
+/// Preset transliteration override tables for [`Slugifier::with_replacements`].
+pub mod presets {
+    /// German overrides, e.g. turning `ü` into `ue` and `ö` into `oe` instead of `deunicode`'s
+    /// defaults.
+    pub const GERMAN: &[(char, &str)] =
+        &[('ß', "ss"), ('ä', "ae"), ('ö', "oe"), ('ü', "ue")];
+
+    /// Scandinavian overrides, e.g. turning `ø` into `oe` and `å` into `aa`.
+    pub const SCANDINAVIAN: &[(char, &str)] =
+        &[('æ', "ae"), ('ø', "oe"), ('å', "aa")];
+}
+
+/// Controls how a `Slugifier` handles characters outside the ASCII alphanumeric range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SlugMode {
+    /// Transliterate non-ASCII characters to their closest ASCII equivalent using `deunicode`.
+    ///
+    /// This is the default mode.
+    #[default]
+    Ascii,
+    /// Keep letters and digits from any script (lowercased) instead of forcing ASCII.
+    ///
+    /// The input is first normalized using Unicode NFKC.
+    Unicode,
+}
 
 /// A utility for converting strings into URL-friendly slugs.
 ///
@@ -18,18 +48,39 @@ use deunicode::deunicode_char;
 /// ```
 #[derive(Clone, Debug)]
 pub struct Slugifier {
-    separator: char,
+    separator: String,
+    mode: SlugMode,
+    word_boundaries: bool,
+    hash_len: Option<usize>,
+    hash_separator: char,
+    hash_prepend: bool,
+    max_length: Option<usize>,
+    ignore: HashSet<char>,
+    replacements: HashMap<char, String>,
 }
 
 impl Default for Slugifier {
-    /// Creates a default `Slugifier` with `-` as the separator.
+    /// Creates a default `Slugifier` with `-` as the separator and `SlugMode::Ascii`.
     fn default() -> Self {
-        Self { separator: '-' }
+        Self {
+            separator: String::from("-"),
+            mode: SlugMode::Ascii,
+            word_boundaries: false,
+            hash_len: None,
+            hash_separator: '-',
+            hash_prepend: false,
+            max_length: None,
+            ignore: HashSet::new(),
+            replacements: HashMap::new(),
+        }
     }
 }
 
 impl Slugifier {
-    /// Sets the separator character to be used in the slug.
+    /// Sets the separator used in the slug.
+    ///
+    /// Accepts anything convertible to a `String`, including a `char`, a multi-character
+    /// joiner, or an empty string to strip word boundaries entirely.
     ///
     /// # Examples
     ///
@@ -39,11 +90,14 @@ impl Slugifier {
     /// let mut slugifier = Slugifier::default();
     /// slugifier.set_separator('_');
     /// ```
-    pub fn set_separator(&mut self, value: char) {
-        self.separator = value;
+    pub fn set_separator(&mut self, value: impl Into<String>) {
+        self.separator = value.into();
     }
 
-    /// Returns a new `Slugifier` with the specified separator character.
+    /// Returns a new `Slugifier` with the specified separator.
+    ///
+    /// Accepts anything convertible to a `String`, including a `char`, a multi-character
+    /// joiner, or an empty string to strip word boundaries entirely.
     ///
     /// # Examples
     ///
@@ -51,18 +105,287 @@ impl Slugifier {
     /// use limace::Slugifier;
     ///
     /// let slugifier = Slugifier::default().with_separator('_');
+    /// assert_eq!(slugifier.slugify("Hello, World!"), "hello_world");
+    ///
+    /// let joined = Slugifier::default().with_separator("");
+    /// assert_eq!(joined.slugify("Madam I'm Adam"), "madamimadam");
     /// ```
-    pub fn with_separator(mut self, value: char) -> Self {
+    pub fn with_separator(mut self, value: impl Into<String>) -> Self {
         self.set_separator(value);
         self
     }
 
+    /// Sets the mode used to handle characters outside the ASCII alphanumeric range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::{SlugMode, Slugifier};
+    ///
+    /// let mut slugifier = Slugifier::default();
+    /// slugifier.set_mode(SlugMode::Unicode);
+    /// ```
+    pub fn set_mode(&mut self, value: SlugMode) {
+        self.mode = value;
+    }
+
+    /// Returns a new `Slugifier` with the specified mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::{SlugMode, Slugifier};
+    ///
+    /// let slugifier = Slugifier::default().with_mode(SlugMode::Unicode);
+    /// assert_eq!(slugifier.slugify("你好世界"), "你好世界");
+    /// ```
+    pub fn with_mode(mut self, value: SlugMode) -> Self {
+        self.set_mode(value);
+        self
+    }
+
+    /// Sets whether camelCase/PascalCase transitions should insert a separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let mut slugifier = Slugifier::default();
+    /// slugifier.set_word_boundaries(true);
+    /// ```
+    pub fn set_word_boundaries(&mut self, value: bool) {
+        self.word_boundaries = value;
+    }
+
+    /// Returns a new `Slugifier` with camelCase/PascalCase word boundary detection enabled or
+    /// disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let slugifier = Slugifier::default().with_word_boundaries(true);
+    /// assert_eq!(slugifier.slugify("parseHTTPResponse"), "parse-http-response");
+    /// ```
+    pub fn with_word_boundaries(mut self, value: bool) -> Self {
+        self.set_word_boundaries(value);
+        self
+    }
+
+    /// Enables a deterministic hash suffix computed from the raw input, hex-encoded and
+    /// truncated to `len` characters (`0` keeps the full hash). This helps keep otherwise
+    /// colliding inputs (e.g. "Hello World!" and "Hello, World") apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let mut slugifier = Slugifier::default();
+    /// slugifier.set_hash(8);
+    /// ```
+    pub fn set_hash(&mut self, len: usize) {
+        self.hash_len = Some(len);
+    }
+
+    /// Returns a new `Slugifier` with a deterministic hash suffix enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let slugifier = Slugifier::default().with_hash(8);
+    /// let slug = slugifier.slugify("Hello, World!");
+    /// assert_eq!(slug.len(), "hello-world".len() + 1 + 8);
+    /// ```
+    pub fn with_hash(mut self, len: usize) -> Self {
+        self.set_hash(len);
+        self
+    }
+
+    /// Sets the separator placed between the slug and the hash suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let mut slugifier = Slugifier::default().with_hash(8);
+    /// slugifier.set_hash_separator('.');
+    /// ```
+    pub fn set_hash_separator(&mut self, value: char) {
+        self.hash_separator = value;
+    }
+
+    /// Returns a new `Slugifier` with the specified hash separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let slugifier = Slugifier::default().with_hash(8).with_hash_separator('.');
+    /// ```
+    pub fn with_hash_separator(mut self, value: char) -> Self {
+        self.set_hash_separator(value);
+        self
+    }
+
+    /// Sets whether the hash suffix is prepended instead of appended to the slug.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let mut slugifier = Slugifier::default().with_hash(8);
+    /// slugifier.set_hash_prepend(true);
+    /// ```
+    pub fn set_hash_prepend(&mut self, value: bool) {
+        self.hash_prepend = value;
+    }
+
+    /// Returns a new `Slugifier` that prepends the hash suffix instead of appending it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let slugifier = Slugifier::default().with_hash(8).with_hash_prepend(true);
+    /// ```
+    pub fn with_hash_prepend(mut self, value: bool) -> Self {
+        self.set_hash_prepend(value);
+        self
+    }
+
+    /// Bounds the length (in bytes) of the produced slug, useful for database columns or
+    /// filesystem limits. Rather than cutting mid-word or mid-codepoint, the writer stops at
+    /// the last separator boundary within the limit. When a hash suffix is also configured, its
+    /// width is reserved from the budget rather than truncated away; only a limit smaller than
+    /// the hash width itself shortens the hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let mut slugifier = Slugifier::default();
+    /// slugifier.set_max_length(8);
+    /// ```
+    pub fn set_max_length(&mut self, value: usize) {
+        self.max_length = Some(value);
+    }
+
+    /// Returns a new `Slugifier` bounded to at most `value` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let slugifier = Slugifier::default().with_max_length(8);
+    /// assert_eq!(slugifier.slugify("Hello, World!"), "hello");
+    /// ```
+    pub fn with_max_length(mut self, value: usize) -> Self {
+        self.set_max_length(value);
+        self
+    }
+
+    /// Sets the set of characters to keep verbatim instead of transliterating them, e.g. to keep
+    /// `你好` while transliterating the rest of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let mut slugifier = Slugifier::default();
+    /// slugifier.set_ignore("你好");
+    /// ```
+    pub fn set_ignore(&mut self, chars: impl AsRef<str>) {
+        self.ignore = chars.as_ref().chars().collect();
+    }
+
+    /// Returns a new `Slugifier` that keeps the given characters verbatim instead of
+    /// transliterating them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let slugifier = Slugifier::default().with_ignore("你好");
+    /// assert_eq!(slugifier.slugify("你好 World"), "你好-world");
+    /// ```
+    pub fn with_ignore(mut self, chars: impl AsRef<str>) -> Self {
+        self.set_ignore(chars);
+        self
+    }
+
+    /// Overrides `deunicode`'s transliteration for a single character, e.g. mapping `ß` to `ss`
+    /// for a German profile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let mut slugifier = Slugifier::default();
+    /// slugifier.set_replacement('ß', "ss");
+    /// ```
+    pub fn set_replacement(&mut self, from: char, to: impl Into<String>) {
+        self.replacements.insert(from, to.into());
+    }
+
+    /// Returns a new `Slugifier` with a transliteration override for a single character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::Slugifier;
+    ///
+    /// let slugifier = Slugifier::default().with_replacement('ß', "ss");
+    /// assert_eq!(slugifier.slugify("Straße"), "strasse");
+    /// ```
+    pub fn with_replacement(mut self, from: char, to: impl Into<String>) -> Self {
+        self.set_replacement(from, to);
+        self
+    }
+
+    /// Loads a preset table of transliteration overrides, such as [`presets::GERMAN`] or
+    /// [`presets::SCANDINAVIAN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use limace::{presets, Slugifier};
+    ///
+    /// let slugifier = Slugifier::default().with_replacements(presets::GERMAN);
+    /// assert_eq!(slugifier.slugify("Straße"), "strasse");
+    /// ```
+    pub fn with_replacements<'t>(
+        mut self,
+        table: impl IntoIterator<Item = &'t (char, &'t str)>,
+    ) -> Self {
+        for (from, to) in table {
+            self.set_replacement(*from, *to);
+        }
+        self
+    }
+
     /// Converts the input into a slug string using the current separator and rules.
     ///
     /// # Rules
     /// - Uppercase letters are converted to lowercase.
-    /// - Unicode characters are transliterated to ASCII using `deunicode`.
-    /// - All non-alphanumeric characters are converted to the separator.
+    /// - In `SlugMode::Ascii` (the default), Unicode characters are transliterated to ASCII
+    ///   using `deunicode`.
+    /// - In `SlugMode::Unicode`, the input is normalized with NFKC and letters/digits from any
+    ///   script, along with the combining marks attached to them, are kept untransliterated.
+    /// - All other characters are converted to the separator.
     /// - Consecutive non-alphanumerics do not produce repeated separators.
     ///
     /// # Examples
@@ -75,9 +398,112 @@ impl Slugifier {
     /// ```
     pub fn slugify(&self, input: impl AsRef<str>) -> String {
         let value = input.as_ref();
-        let mut writer = SlugWriter::new(self, value.len());
+
+        // Reserve room for the hash suffix so the budget governs the final output, not just the
+        // slug portion.
+        let budget = self.max_length.map(|limit| {
+            let reserved = self
+                .hash_len
+                .map_or(0, |len| 1 + if len == 0 { FULL_HASH_LEN } else { len });
+            limit.saturating_sub(reserved)
+        });
+
+        let mut writer = SlugWriter::new(self, value.len(), budget);
         writer.push_str(value);
-        writer.into_inner()
+        let slug = writer.into_inner();
+
+        match self.hash_len {
+            // The hash is computed from the raw input, not the slug, so that two inputs
+            // collapsing to the same slug still end up with distinct suffixes.
+            Some(len) => self.append_hash(slug, value, len),
+            None => slug,
+        }
+    }
+
+    fn append_hash(&self, slug: String, input: &str, len: usize) -> String {
+        let full = format!("{:016x}", fx_hash(input.as_bytes()));
+        let mut hash_len = if len == 0 || len >= full.len() {
+            full.len()
+        } else {
+            len
+        };
+
+        // The pre-reservation in `slugify` only covers the common case; if `max_length` is too
+        // small to fit the slug plus the requested hash width, shrink the hash further instead of
+        // silently producing output longer than the configured limit.
+        if let Some(limit) = self.max_length {
+            let separator_len = if slug.is_empty() {
+                0
+            } else {
+                self.hash_separator.len_utf8()
+            };
+            let available = limit.saturating_sub(slug.len() + separator_len);
+            hash_len = hash_len.min(available);
+        }
+
+        let hash = &full[..hash_len];
+
+        if hash.is_empty() {
+            slug
+        } else if slug.is_empty() {
+            hash.to_string()
+        } else if self.hash_prepend {
+            format!("{hash}{}{slug}", self.hash_separator)
+        } else {
+            format!("{slug}{}{hash}", self.hash_separator)
+        }
+    }
+}
+
+/// The number of hex characters produced by [`fx_hash`] before truncation.
+const FULL_HASH_LEN: usize = 16;
+
+/// A tiny, fast, non-cryptographic hash used to derive the deterministic hash suffix. It is not
+/// suitable for untrusted input, but it is stable across runs and platforms.
+fn fx_hash(bytes: &[u8]) -> u64 {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    let mut hash: u64 = 0;
+    for chunk in bytes.chunks(8) {
+        let mut buf = [0u8; 8];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let word = u64::from_le_bytes(buf);
+        hash = (hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+    hash
+}
+
+/// The class of an *untransformed* character, used to detect camelCase/PascalCase boundaries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Other,
+}
+
+impl CharClass {
+    fn of(value: char) -> Self {
+        match value {
+            'a'..='z' => Self::Lower,
+            'A'..='Z' => Self::Upper,
+            '0'..='9' => Self::Digit,
+            _ => Self::Other,
+        }
+    }
+
+    /// Same classification as [`Self::of`], but for characters outside the ASCII range, used by
+    /// `push_unicode_char` so word-boundary splitting also works in [`SlugMode::Unicode`].
+    fn of_unicode(value: char) -> Self {
+        if value.is_uppercase() {
+            Self::Upper
+        } else if value.is_lowercase() {
+            Self::Lower
+        } else if value.is_numeric() {
+            Self::Digit
+        } else {
+            Self::Other
+        }
     }
 }
 
@@ -85,33 +511,114 @@ struct SlugWriter<'a> {
     options: &'a Slugifier,
     buffer: String,
     previous_separator: bool,
+    prev_class: CharClass,
+    upper_streak: u32,
+    limit: Option<usize>,
+    // Buffer length at the last separator boundary, used to cut whole words when truncating.
+    last_boundary: usize,
+    truncated: bool,
 }
 
 impl<'a> SlugWriter<'a> {
-    fn new(options: &'a Slugifier, size: usize) -> Self {
+    fn new(options: &'a Slugifier, size: usize, limit: Option<usize>) -> Self {
         Self {
             options,
             buffer: String::with_capacity(size),
             // to avoid leading separator
             previous_separator: true,
+            prev_class: CharClass::Other,
+            upper_streak: 0,
+            limit,
+            last_boundary: 0,
+            truncated: false,
         }
     }
 
+    /// Whether `additional` more bytes would still fit within the configured limit.
+    fn has_room(&self, additional: usize) -> bool {
+        self.limit
+            .is_none_or(|limit| self.buffer.len() + additional <= limit)
+    }
+
     fn push_separator(&mut self) {
+        if self.truncated {
+            return;
+        }
+
         if !self.previous_separator {
-            self.buffer.push(self.options.separator);
+            if !self.options.separator.is_empty() {
+                if !self.has_room(self.options.separator.len()) {
+                    // The word just written is complete, so keep it when truncating.
+                    self.last_boundary = self.buffer.len();
+                    self.truncated = true;
+                    return;
+                }
+                self.buffer.push_str(&self.options.separator);
+            }
             self.previous_separator = true;
         }
+        self.last_boundary = self.buffer.len();
+    }
+
+    /// Pushes a character verbatim, bypassing transliteration entirely (used for the ignore set).
+    fn push_verbatim(&mut self, value: char) {
+        if self.truncated {
+            return;
+        }
+
+        if !self.has_room(value.len_utf8()) {
+            self.truncated = true;
+            return;
+        }
+
+        self.previous_separator = false;
+        self.buffer.push(value);
+        self.upper_streak = 0;
+        self.prev_class = CharClass::Other;
+    }
+
+    /// Splits the word boundary that was already pushed, moving the separator before the last
+    /// character in the buffer instead of the current one (e.g. the `R` in `HTTPResponse`).
+    fn split_before_last(&mut self) {
+        if let Some(last) = self.buffer.pop() {
+            self.push_separator();
+            self.buffer.push(last);
+            self.previous_separator = false;
+        }
     }
 
     fn push_char(&mut self, value: char) {
+        if self.truncated {
+            return;
+        }
+
+        let class = CharClass::of(value);
+
+        if self.options.word_boundaries {
+            if class == CharClass::Upper
+                && matches!(self.prev_class, CharClass::Lower | CharClass::Digit)
+            {
+                self.push_separator();
+            } else if class == CharClass::Lower && self.upper_streak >= 2 {
+                self.split_before_last();
+            }
+        }
+
         match value {
             'a'..='z' | '0'..='9' => {
+                if !self.has_room(1) {
+                    self.truncated = true;
+                    return;
+                }
                 self.previous_separator = false;
                 self.buffer.push(value);
             }
 
             'A'..='Z' => {
+                if !self.has_room(1) {
+                    self.truncated = true;
+                    return;
+                }
                 self.previous_separator = false;
 
                 // Manual lowercasing as Rust to_lowercase() is unicode aware and therefore much slower
@@ -123,20 +630,131 @@ impl<'a> SlugWriter<'a> {
                 self.push_separator();
             }
         }
+
+        self.upper_streak = if class == CharClass::Upper {
+            self.upper_streak + 1
+        } else {
+            0
+        };
+        self.prev_class = class;
+    }
+
+    /// Pushes a character that has already survived Unicode normalization, keeping letters and
+    /// digits from any script instead of restricting them to ASCII.
+    fn push_unicode_char(&mut self, value: char) {
+        use GeneralCategory::*;
+
+        if self.truncated {
+            return;
+        }
+
+        match get_general_category(value) {
+            LowercaseLetter | UppercaseLetter | TitlecaseLetter | ModifierLetter
+            | OtherLetter | LetterNumber | DecimalNumber | OtherNumber => {
+                let class = CharClass::of_unicode(value);
+
+                if self.options.word_boundaries {
+                    if class == CharClass::Upper
+                        && matches!(self.prev_class, CharClass::Lower | CharClass::Digit)
+                    {
+                        self.push_separator();
+                    } else if class == CharClass::Lower && self.upper_streak >= 2 {
+                        self.split_before_last();
+                    }
+                }
+
+                let lower: String = value.to_lowercase().collect();
+                if !self.has_room(lower.len()) {
+                    self.truncated = true;
+                    return;
+                }
+                self.previous_separator = false;
+                self.buffer.push_str(&lower);
+
+                self.upper_streak = if class == CharClass::Upper {
+                    self.upper_streak + 1
+                } else {
+                    0
+                };
+                self.prev_class = class;
+            }
+            // Combining marks (e.g. Devanagari vowel signs, Thai tone/vowel marks) attach to the
+            // letter they follow: they are kept as slug content rather than treated as a
+            // separator boundary, and they do not affect word-boundary tracking.
+            NonspacingMark | SpacingMark | EnclosingMark => {
+                let lower: String = value.to_lowercase().collect();
+                if !self.has_room(lower.len()) {
+                    self.truncated = true;
+                    return;
+                }
+                self.previous_separator = false;
+                self.buffer.push_str(&lower);
+            }
+            _ => {
+                self.push_separator();
+            }
+        }
     }
 
     fn push_str(&mut self, value: &str) {
-        for c in value.chars() {
-            match deunicode_char(c) {
-                Some(value) => value.chars().for_each(|uc| self.push_char(uc)),
-                None => self.push_separator(),
+        match self.options.mode {
+            SlugMode::Ascii => {
+                for c in value.chars() {
+                    if self.truncated {
+                        break;
+                    }
+                    if self.options.ignore.contains(&c) {
+                        self.push_verbatim(c);
+                    } else if let Some(replacement) = self.options.replacements.get(&c) {
+                        replacement.clone().chars().for_each(|rc| self.push_char(rc));
+                    } else {
+                        match deunicode_char(c) {
+                            Some(value) => value.chars().for_each(|uc| self.push_char(uc)),
+                            None => self.push_separator(),
+                        }
+                    }
+                }
+            }
+            SlugMode::Unicode => {
+                for c in value.nfkc() {
+                    if self.truncated {
+                        break;
+                    }
+                    if self.options.ignore.contains(&c) {
+                        self.push_verbatim(c);
+                    } else if let Some(replacement) = self.options.replacements.get(&c) {
+                        replacement
+                            .clone()
+                            .chars()
+                            .for_each(|rc| self.push_unicode_char(rc));
+                    } else {
+                        self.push_unicode_char(c);
+                    }
+                }
             }
         }
     }
 
     fn into_inner(mut self) -> String {
-        if self.buffer.ends_with(self.options.separator) {
-            self.buffer.pop();
+        // If truncation cut a word in half, fall back to the last separator boundary so whole
+        // words are preserved. That boundary is, by construction, right after a real separator
+        // push, so the buffer now ends exactly where `previous_separator` claims it does.
+        if self.truncated && self.last_boundary < self.buffer.len() {
+            self.buffer.truncate(self.last_boundary);
+            self.previous_separator = true;
+        }
+
+        // Trim a trailing separator, but only the one `push_separator` actually inserted:
+        // `last_boundary` marks the buffer length right after that push, so matching it against
+        // the current length (with `previous_separator` set) tells real separators apart from
+        // slug content that merely happens to match the separator string.
+        if !self.options.separator.is_empty()
+            && self.previous_separator
+            && self.last_boundary > 0
+            && self.buffer.len() == self.last_boundary
+        {
+            self.buffer
+                .truncate(self.last_boundary - self.options.separator.len());
         }
         self.buffer.shrink_to_fit();
         self.buffer
@@ -196,4 +814,218 @@ mod tests {
         let twice = slugifier.slugify(&once);
         assert_eq!(once, twice);
     }
+
+    #[test]
+    fn should_keep_non_latin_scripts_in_unicode_mode() {
+        let slugifier = Slugifier::default().with_mode(SlugMode::Unicode);
+        assert_eq!(slugifier.slugify("你好世界"), "你好世界");
+    }
+
+    #[test]
+    fn should_lowercase_and_separate_in_unicode_mode() {
+        let slugifier = Slugifier::default().with_mode(SlugMode::Unicode);
+        assert_eq!(slugifier.slugify("Café Ñandú"), "café-ñandú");
+    }
+
+    #[test]
+    fn should_keep_devanagari_combining_marks_in_unicode_mode() {
+        let slugifier = Slugifier::default().with_mode(SlugMode::Unicode);
+        assert_eq!(slugifier.slugify("नमस्ते दुनिया"), "नमस्ते-दुनिया");
+    }
+
+    #[test]
+    fn should_keep_thai_combining_marks_in_unicode_mode() {
+        let slugifier = Slugifier::default().with_mode(SlugMode::Unicode);
+        assert_eq!(slugifier.slugify("สวัสดีชาวโลก"), "สวัสดีชาวโลก");
+    }
+
+    #[test]
+    fn should_still_transliterate_in_ascii_mode() {
+        let slugifier = Slugifier::default().with_mode(SlugMode::Ascii);
+        assert_eq!(slugifier.slugify("Crème brûlée"), "creme-brulee");
+    }
+
+    #[test]
+    fn should_handle_empty_separator() {
+        let slugifier = Slugifier::default().with_separator("");
+        assert_eq!(slugifier.slugify("Madam I'm Adam"), "madamimadam");
+    }
+
+    #[test]
+    fn should_handle_multi_character_separator() {
+        let slugifier = Slugifier::default().with_separator("__");
+        assert_eq!(slugifier.slugify("Hello, World!"), "hello__world");
+    }
+
+    #[test]
+    fn should_trim_multi_character_separator_at_edges() {
+        let slugifier = Slugifier::default().with_separator("::");
+        assert_eq!(slugifier.slugify("...Hello World..."), "hello::world");
+    }
+
+    #[test]
+    fn should_not_strip_trailing_content_matching_an_alphanumeric_separator() {
+        let slugifier = Slugifier::default().with_separator("an");
+        assert_eq!(slugifier.slugify("urban"), "urban");
+
+        let slugifier = Slugifier::default().with_separator("23");
+        assert_eq!(slugifier.slugify("room123"), "room123");
+
+        let slugifier = Slugifier::default()
+            .with_mode(SlugMode::Unicode)
+            .with_separator("好");
+        assert_eq!(slugifier.slugify("你好"), "你好");
+    }
+
+    #[test]
+    fn should_split_camel_case_word_boundaries() {
+        let slugifier = Slugifier::default().with_word_boundaries(true);
+        assert_eq!(slugifier.slugify("fooBar"), "foo-bar");
+    }
+
+    #[test]
+    fn should_split_pascal_case_with_acronym() {
+        let slugifier = Slugifier::default().with_word_boundaries(true);
+        assert_eq!(slugifier.slugify("parseHTTPResponse"), "parse-http-response");
+    }
+
+    #[test]
+    fn should_split_camel_case_word_boundaries_in_unicode_mode() {
+        let slugifier = Slugifier::default()
+            .with_mode(SlugMode::Unicode)
+            .with_word_boundaries(true);
+        assert_eq!(slugifier.slugify("fooÉtage"), "foo-étage");
+    }
+
+    #[test]
+    fn should_not_double_separator_at_existing_boundary() {
+        let slugifier = Slugifier::default().with_word_boundaries(true);
+        assert_eq!(slugifier.slugify("foo Bar"), "foo-bar");
+    }
+
+    #[test]
+    fn should_leave_case_untouched_without_word_boundaries() {
+        let slugifier = Slugifier::default();
+        assert_eq!(slugifier.slugify("fooBar"), "foobar");
+    }
+
+    #[test]
+    fn should_append_deterministic_hash_suffix() {
+        let slugifier = Slugifier::default().with_hash(8);
+        let once = slugifier.slugify("Hello, World!");
+        let twice = slugifier.slugify("Hello, World!");
+        assert_eq!(once, twice);
+        assert!(once.starts_with("hello-world-"));
+        assert_eq!(once.len(), "hello-world-".len() + 8);
+    }
+
+    #[test]
+    fn should_give_colliding_slugs_distinct_hash_suffixes() {
+        let slugifier = Slugifier::default().with_hash(8);
+        let first = slugifier.slugify("Hello World!");
+        let second = slugifier.slugify("Hello, World");
+        assert_ne!(first, second);
+        assert_eq!(
+            first.rsplit_once('-').map(|(slug, _)| slug),
+            second.rsplit_once('-').map(|(slug, _)| slug)
+        );
+    }
+
+    #[test]
+    fn should_prepend_hash_when_configured() {
+        let slugifier = Slugifier::default().with_hash(4).with_hash_prepend(true);
+        let slug = slugifier.slugify("Hello, World!");
+        assert!(slug.ends_with("-hello-world"));
+    }
+
+    #[test]
+    fn should_use_custom_hash_separator() {
+        let slugifier = Slugifier::default()
+            .with_hash(4)
+            .with_hash_separator('.');
+        let slug = slugifier.slugify("Hello, World!");
+        assert!(slug.starts_with("hello-world."));
+    }
+
+    #[test]
+    fn should_truncate_at_word_boundary() {
+        let slugifier = Slugifier::default().with_max_length(8);
+        assert_eq!(slugifier.slugify("Hello, World!"), "hello");
+    }
+
+    #[test]
+    fn should_not_truncate_when_under_the_limit() {
+        let slugifier = Slugifier::default().with_max_length(64);
+        assert_eq!(slugifier.slugify("Hello, World!"), "hello-world");
+    }
+
+    #[test]
+    fn should_not_split_a_multi_byte_char_when_truncating() {
+        let slugifier = Slugifier::default()
+            .with_mode(SlugMode::Unicode)
+            .with_max_length(4);
+        let slug = slugifier.slugify("日本語 abc");
+        assert!(slug.is_char_boundary(slug.len()));
+    }
+
+    #[test]
+    fn should_reserve_hash_budget_from_max_length() {
+        let slugifier = Slugifier::default().with_max_length(12).with_hash(4);
+        let slug = slugifier.slugify("Hello, World!");
+        assert!(slug.len() <= 12);
+        let (_, hash) = slug.rsplit_once('-').expect("hash separator present");
+        assert_eq!(hash.len(), 4);
+    }
+
+    #[test]
+    fn should_shrink_hash_when_max_length_is_smaller_than_reserved_width() {
+        let slugifier = Slugifier::default().with_max_length(2).with_hash(4);
+        let slug = slugifier.slugify("Hello, World!");
+        assert!(
+            slug.len() <= 2,
+            "slug {slug:?} exceeds the configured max_length"
+        );
+    }
+
+    #[test]
+    fn should_keep_ignored_characters_verbatim() {
+        let slugifier = Slugifier::default().with_ignore("你好");
+        assert_eq!(slugifier.slugify("你好 World"), "你好-world");
+    }
+
+    #[test]
+    fn should_apply_single_replacement_override() {
+        let slugifier = Slugifier::default().with_replacement('ß', "ss");
+        assert_eq!(slugifier.slugify("Straße"), "strasse");
+    }
+
+    #[test]
+    fn should_apply_preset_replacement_table() {
+        let slugifier = Slugifier::default().with_replacements(presets::GERMAN);
+        assert_eq!(slugifier.slugify("Straße"), "strasse");
+        assert_eq!(slugifier.slugify("Grüße"), "gruesse");
+    }
+
+    #[test]
+    fn should_apply_scandinavian_preset() {
+        let slugifier = Slugifier::default().with_replacements(presets::SCANDINAVIAN);
+        assert_eq!(slugifier.slugify("Blåbær"), "blaabaer");
+    }
+
+    #[test]
+    fn should_keep_ignored_characters_verbatim_in_unicode_mode() {
+        let slugifier = Slugifier::default()
+            .with_mode(SlugMode::Unicode)
+            .with_ignore("你好");
+        assert_eq!(slugifier.slugify("你好 World"), "你好-world");
+    }
+
+    #[test]
+    fn should_apply_preset_replacement_table_in_unicode_mode() {
+        let slugifier = Slugifier::default()
+            .with_mode(SlugMode::Unicode)
+            .with_replacements(presets::GERMAN);
+        assert_eq!(slugifier.slugify("Straße"), "strasse");
+        assert_eq!(slugifier.slugify("Grüße"), "gruesse");
+    }
 }